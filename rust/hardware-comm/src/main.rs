@@ -12,10 +12,12 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, Paragraph},
     Terminal,
 };
-use redis::Commands;
+use futures_util::StreamExt;
+use redis::AsyncCommands;
 use serde::{Deserialize, Serialize};
 use serialport::SerialPort;
-use std::io::{self, BufRead, BufReader};
+use std::collections::VecDeque;
+use std::io::{self, Read};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
@@ -25,6 +27,21 @@ use tracing::{error, info, warn};
 const DEFAULT_REDIS_SOCKET_PATH: &str = "./redis.sock";
 const REDIS_IN_CHANNEL: &str = "hardware:in";
 const REDIS_OUT_CHANNEL: &str = "hardware:out";
+const REDIS_EVENTS_STREAM: &str = "hardware:events";
+const DEFAULT_STREAM_MAXLEN: usize = 10_000;
+const OUTBOX_CAPACITY: usize = 1024;
+/// Consumer group name for at-least-once delivery of lap events off
+/// `REDIS_EVENTS_STREAM`. See `stream_consumer_task`.
+const REDIS_EVENTS_GROUP: &str = "lapcounter-scoreboard";
+/// Fixed consumer name within `REDIS_EVENTS_GROUP`. Only one `hardware-comm`
+/// process owns this stream's consumption at a time, so a stable name (not
+/// one derived from the process id) is what lets a restart see its own
+/// prior pending entries via `XREADGROUP ... 0` and actually replay them.
+const REDIS_EVENTS_CONSUMER: &str = "primary";
+
+// Serial framing
+const FRAME_BUFFER_SIZE: usize = 8192;
+const FRAME_START: u8 = 0x01;
 
 // Message types
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -61,7 +78,338 @@ enum InMessage {
         sensor_id: Option<u32>,
         #[serde(skip_serializing_if = "Option::is_none")]
         race_time: Option<f64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        baudrate: Option<u32>,
+    },
+}
+
+/// A parsed, actionable form of `InMessage::Command`'s free-form `command`
+/// string, dispatched by `command_handler_task` against `App`/`HardwareComm`.
+#[derive(Debug, Clone, PartialEq)]
+enum HardwareCommand {
+    StartRace,
+    StopRace,
+    SimulateLap {
+        racer_id: u32,
+        sensor_id: u32,
+        race_time: f64,
     },
+    Reset,
+    SetBaudrate(u32),
+}
+
+impl HardwareCommand {
+    /// Returns `None` for an unrecognized `command` string; the caller logs
+    /// that case rather than treating it as a parse error.
+    fn parse(msg: InMessage) -> Option<Self> {
+        let InMessage::Command {
+            command,
+            racer_id,
+            sensor_id,
+            race_time,
+            baudrate,
+        } = msg;
+
+        match command.as_str() {
+            "start_race" => Some(HardwareCommand::StartRace),
+            "stop_race" => Some(HardwareCommand::StopRace),
+            "simulate_lap" => Some(HardwareCommand::SimulateLap {
+                racer_id: racer_id.unwrap_or(1),
+                sensor_id: sensor_id.unwrap_or(1),
+                race_time: race_time.unwrap_or(0.0),
+            }),
+            "reset" => Some(HardwareCommand::Reset),
+            "set_baudrate" => baudrate.map(HardwareCommand::SetBaudrate),
+            _ => None,
+        }
+    }
+}
+
+/// Classifies the faults `HardwareComm` can hit on the serial and Redis
+/// paths, distinguishing conditions `hardware_task` can retry through from
+/// ones that should take the app down.
+#[derive(Debug)]
+enum HardwareCommError {
+    SerialOpen(String),
+    SerialRead(String),
+    RedisConnect(String),
+    RedisPublish(String),
+    Parse(String),
+}
+
+impl HardwareCommError {
+    /// Whether `hardware_task` should back off and retry rather than give
+    /// up. Everything here is a transient condition of the serial link or
+    /// Redis; a `Parse` failure means the frame itself is malformed and
+    /// retrying the same bytes would never help.
+    fn is_recoverable(&self) -> bool {
+        !matches!(self, HardwareCommError::Parse(_))
+    }
+}
+
+impl std::fmt::Display for HardwareCommError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HardwareCommError::SerialOpen(msg) => write!(f, "failed to open serial port: {}", msg),
+            HardwareCommError::SerialRead(msg) => write!(f, "serial read error: {}", msg),
+            HardwareCommError::RedisConnect(msg) => write!(f, "failed to connect to Redis: {}", msg),
+            HardwareCommError::RedisPublish(msg) => write!(f, "failed to publish to Redis: {}", msg),
+            HardwareCommError::Parse(msg) => write!(f, "failed to parse hardware frame: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for HardwareCommError {}
+
+/// One complete, delimited frame read off the serial port: the leading
+/// `\x01` sentinel is still present, only the `\r\n` terminator is stripped.
+/// Owned so it can outlive the `push_bytes` call that produced it.
+type Frame = Vec<u8>;
+
+/// A fixed-capacity, reuse-friendly framer: accumulates raw bytes read from
+/// the serial port and yields complete Franklin protocol frames, each
+/// delimited by a `\x01` start sentinel and a `\r\n` terminator. Frames split
+/// across two reads (and non-UTF-8 bytes inside them) are handled
+/// transparently: no byte is ever dropped, and a trailing partial frame is
+/// carried over to the next `push_bytes` call rather than reallocating.
+struct SerialFramer {
+    buf: [u8; FRAME_BUFFER_SIZE],
+    len: usize,
+    /// Set when a frame never terminated before filling the buffer; taken
+    /// (and cleared) by `take_overflow`.
+    overflow: Option<&'static str>,
+}
+
+impl SerialFramer {
+    fn new() -> Self {
+        Self {
+            buf: [0u8; FRAME_BUFFER_SIZE],
+            len: 0,
+            overflow: None,
+        }
+    }
+
+    /// Appends freshly read bytes and returns every frame that is now
+    /// complete. If a frame never terminates before filling the buffer, the
+    /// buffered bytes are dropped and the overflow is recorded for
+    /// `take_overflow` so a stuck sentinel can't wedge the framer forever.
+    fn push_bytes(&mut self, mut data: &[u8]) -> impl Iterator<Item = Frame> {
+        let mut frames = Vec::new();
+        loop {
+            // Copy only what fits in the free region, draining complete
+            // frames to make room before taking the rest of `data`.
+            let take = data.len().min(self.buf.len() - self.len);
+            self.buf[self.len..self.len + take].copy_from_slice(&data[..take]);
+            self.len += take;
+            data = &data[take..];
+            self.drain_frames(|frame| frames.push(frame.to_vec()));
+
+            if data.is_empty() {
+                break;
+            }
+            if self.len == self.buf.len() {
+                // The buffer holds a single frame that never terminated.
+                self.len = 0;
+                self.overflow = Some("serial frame exceeded buffer capacity, discarding");
+            }
+        }
+        frames.into_iter()
+    }
+
+    /// Returns and clears the pending overflow reason, if any.
+    fn take_overflow(&mut self) -> Option<&'static str> {
+        self.overflow.take()
+    }
+
+    /// Scans the buffered bytes for complete frames and invokes `on_frame`
+    /// for each one (leading sentinel kept, `\r\n` terminator stripped). Any
+    /// bytes preceding the first sentinel are discarded as noise. A trailing
+    /// partial frame is moved to the front of the buffer so the next
+    /// `push_bytes` appends after it.
+    fn drain_frames(&mut self, mut on_frame: impl FnMut(&[u8])) {
+        let mut cursor = 0;
+        let mut consumed = 0;
+
+        loop {
+            let frame_start = match self.buf[cursor..self.len]
+                .iter()
+                .position(|&b| b == FRAME_START)
+            {
+                Some(offset) => cursor + offset,
+                None => {
+                    consumed = self.len;
+                    break;
+                }
+            };
+
+            let search_from = frame_start + 1;
+            match self.buf[search_from..self.len]
+                .windows(2)
+                .position(|w| w == b"\r\n")
+            {
+                Some(offset) => {
+                    let frame_end = search_from + offset;
+                    on_frame(&self.buf[frame_start..frame_end]);
+                    consumed = frame_end + 2;
+                    cursor = consumed;
+                }
+                None => {
+                    // Incomplete frame - keep the bytes from its sentinel on,
+                    // wait for the rest to arrive on a future read.
+                    consumed = frame_start;
+                    break;
+                }
+            }
+        }
+
+        if consumed > 0 {
+            self.buf.copy_within(consumed..self.len, 0);
+            self.len -= consumed;
+        }
+    }
+}
+
+/// A source of bytes the frame decoder can read from. Implemented by
+/// `Box<dyn SerialPort>` for real hardware and by `MockSource` in tests, so
+/// the decode loop doesn't care whether it's reading a serial port or a
+/// recorded capture sliced at arbitrary boundaries.
+trait ByteSource {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize>;
+
+    fn write_all(&mut self, _buf: &[u8]) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl ByteSource for Box<dyn SerialPort> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        Read::read(self.as_mut(), buf)
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        io::Write::write_all(self.as_mut(), buf)
+    }
+}
+
+/// The `type` field recorded on each `hardware:events` stream entry, so a
+/// consumer reading the stream can filter without deserializing `payload`.
+fn out_message_type(msg: &OutMessage) -> &'static str {
+    match msg {
+        OutMessage::Lap { .. } => "lap",
+        OutMessage::Heartbeat => "heartbeat",
+        OutMessage::Status { .. } => "status",
+        OutMessage::Error { .. } => "error",
+        OutMessage::Debug { .. } => "debug",
+        OutMessage::Raw { .. } => "raw",
+    }
+}
+
+/// Parses one already-delimited frame produced by `SerialFramer` (leading
+/// `\x01` sentinel still present, `\r\n` terminator already stripped) -
+/// `starts_with(b"\x01...")` below relies on the sentinel being there. Only
+/// the numeric payload fields are decoded as ASCII; everything else is
+/// matched on raw bytes so a stray non-UTF-8 byte elsewhere in the frame
+/// can't abort parsing.
+fn decode_frame(frame: &[u8]) -> Option<OutMessage> {
+    if frame.is_empty() {
+        return None;
+    }
+
+    let field_str = |bytes: &[u8]| String::from_utf8_lossy(bytes).into_owned();
+
+    if frame.starts_with(b"\x01#") && frame.windows(5).any(|w| w == b"xC249") {
+        // Heartbeat
+        Some(OutMessage::Heartbeat)
+    } else if frame.starts_with(b"\x01@") {
+        // Lap message: \x01@\t<sensor_id>\t...\t<racer_id>\t<race_time>\t...
+        let parts: Vec<&[u8]> = frame.split(|&b| b == b'\t').collect();
+        if parts.len() >= 6 {
+            match (parts.get(3), parts.get(1), parts.get(4)) {
+                (Some(racer_id_bytes), Some(sensor_id_bytes), Some(race_time_bytes)) => {
+                    if let (Ok(racer_id), Ok(sensor_id), Ok(race_time)) = (
+                        field_str(racer_id_bytes).parse::<u32>(),
+                        field_str(sensor_id_bytes).parse::<u32>(),
+                        field_str(race_time_bytes).parse::<f64>(),
+                    ) {
+                        return Some(OutMessage::Lap {
+                            racer_id,
+                            sensor_id,
+                            race_time,
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+        Some(OutMessage::Status {
+            message: format!("Malformed lap line: {}", field_str(frame)),
+        })
+    } else if frame.starts_with(b"\x01$") {
+        // New message: \x01$\t<sensor_id>\t<raw_time>\t<flag1>\t<flag2>
+        let parts: Vec<&[u8]> = frame.split(|&b| b == b'\t').collect();
+        if parts.len() >= 5 {
+            // Just send as raw for now - we can add a NewMsg variant if needed
+            Some(OutMessage::Raw {
+                line: field_str(frame),
+            })
+        } else {
+            Some(OutMessage::Status {
+                message: format!("Malformed new_msg line: {}", field_str(frame)),
+            })
+        }
+    } else {
+        Some(OutMessage::Raw {
+            line: field_str(frame),
+        })
+    }
+}
+
+/// Result of one `read_and_decode` call: the messages decoded from
+/// now-complete frames, plus a description of the buffer overflow if the
+/// ring buffer had to be reset because a frame never terminated.
+struct DecodedRead {
+    messages: Vec<OutMessage>,
+    overflow: Option<&'static str>,
+}
+
+/// Reads once from `source` into `scratch`, feeds the bytes through
+/// `framer`, and returns every decoded `OutMessage` produced by now-complete
+/// frames. Generic over `ByteSource` so it is exercised directly in tests
+/// against a `MockSource`, independent of the real serial port and Redis.
+fn read_and_decode<S: ByteSource>(
+    source: &mut S,
+    framer: &mut SerialFramer,
+    scratch: &mut [u8],
+) -> io::Result<DecodedRead> {
+    let n = source.read(scratch)?;
+    let mut messages = Vec::new();
+    if n > 0 {
+        for frame in framer.push_bytes(&scratch[..n]) {
+            if let Some(msg) = decode_frame(&frame) {
+                messages.push(msg);
+            }
+        }
+    }
+    let overflow = framer.take_overflow();
+    if let Some(reason) = overflow {
+        warn!("{}", reason);
+    }
+    Ok(DecodedRead { messages, overflow })
+}
+
+/// Builds a `unix://` Redis connection URL from a plain socket path, making
+/// a relative path absolute first since the path becomes part of the URL
+/// itself rather than being resolved against a working directory later.
+fn unix_socket_url(redis_socket_path: &str) -> Result<String> {
+    let socket_path = if redis_socket_path.starts_with('/') {
+        redis_socket_path.to_string()
+    } else {
+        std::env::current_dir()?
+            .join(redis_socket_path)
+            .to_string_lossy()
+            .to_string()
+    };
+    Ok(format!("unix://{}", socket_path))
 }
 
 // Application state
@@ -119,56 +467,92 @@ impl App {
 // Hardware communication handler
 struct HardwareComm {
     redis_client: redis::Client,
+    /// Cheaply `Clone`-able multiplexed connection shared by every publish
+    /// path; the redis crate pipelines concurrent commands over it rather
+    /// than blocking a tokio worker thread per round-trip.
+    conn: redis::aio::MultiplexedConnection,
     simulation_mode: bool,
     serial_port_path: Option<String>,
-    baudrate: u32,
+    /// Serial baud rate. Runtime-adjustable via `HardwareCommand::SetBaudrate`;
+    /// `open_serial_connection` reads the current value, so a change takes
+    /// effect on the next reconnect rather than on the open port.
+    baudrate: std::sync::atomic::AtomicU32,
+    stream_maxlen: usize,
+    /// Messages that failed to publish, queued for the background flush
+    /// loop to retry once Redis recovers. See `enqueue_outbound`.
+    outbox: std::sync::Mutex<VecDeque<OutMessage>>,
+    /// Set by `HardwareCommand::Reset`; `hardware_task` polls this alongside
+    /// `App::should_quit` and sends the reset sequence down the open port.
+    reset_requested: std::sync::atomic::AtomicBool,
 }
 
 impl HardwareComm {
-    fn new(
+    /// `redis_url` is a full connection URL (`unix://`, `redis://host:port`,
+    /// or `rediss://host:port` for TLS - the latter needs the crate's
+    /// `tokio-native-tls-comp` feature enabled). Use `unix_socket_url` to
+    /// build one from a plain socket path.
+    async fn new(
         simulation_mode: bool,
-        redis_socket_path: &str,
+        redis_url: &str,
         serial_port_path: Option<String>,
         baudrate: u32,
+        stream_maxlen: usize,
     ) -> Result<Self> {
-        // Convert relative path to absolute for Redis client
-        let socket_path = if redis_socket_path.starts_with('/') {
-            // Already absolute
-            redis_socket_path.to_string()
-        } else {
-            // Make it absolute
-            std::env::current_dir()?
-                .join(redis_socket_path)
-                .to_string_lossy()
-                .to_string()
-        };
+        let redis_client =
+            redis::Client::open(redis_url).context("Failed to create Redis client")?;
 
-        let redis_client = redis::Client::open(format!("unix://{}", socket_path))
-            .context("Failed to create Redis client")?;
+        let conn = redis_client
+            .get_multiplexed_tokio_connection()
+            .await
+            .context("Failed to open multiplexed Redis connection")?;
 
         Ok(Self {
             redis_client,
+            conn,
             simulation_mode,
             serial_port_path,
-            baudrate,
+            baudrate: std::sync::atomic::AtomicU32::new(baudrate),
+            stream_maxlen,
+            outbox: std::sync::Mutex::new(VecDeque::new()),
+            reset_requested: std::sync::atomic::AtomicBool::new(false),
         })
     }
 
-    fn open_serial_connection(&self) -> Result<Box<dyn SerialPort>> {
-        let port_path = self
-            .serial_port_path
-            .as_ref()
-            .context("No serial port specified")?;
+    /// Queue a baud rate change, applied by `open_serial_connection` the
+    /// next time the port is (re)opened.
+    fn set_baudrate(&self, baudrate: u32) {
+        self.baudrate
+            .store(baudrate, std::sync::atomic::Ordering::Relaxed);
+    }
+
+    /// Ask `hardware_task` to send the reset sequence down the open port.
+    fn request_reset(&self) {
+        self.reset_requested
+            .store(true, std::sync::atomic::Ordering::Relaxed);
+    }
+
+    /// Take and clear the pending reset request, if any.
+    fn take_reset_request(&self) -> bool {
+        self.reset_requested
+            .swap(false, std::sync::atomic::Ordering::Relaxed)
+    }
+
+    fn open_serial_connection(&self) -> Result<Box<dyn SerialPort>, HardwareCommError> {
+        let port_path = self.serial_port_path.as_ref().ok_or_else(|| {
+            HardwareCommError::SerialOpen("no serial port specified".to_string())
+        })?;
+
+        let baudrate = self.baudrate.load(std::sync::atomic::Ordering::Relaxed);
 
         info!(
             "Opening serial connection to {} at {} baud",
-            port_path, self.baudrate
+            port_path, baudrate
         );
 
-        let port = serialport::new(port_path, self.baudrate)
+        let port = serialport::new(port_path, baudrate)
             .timeout(Duration::from_secs(1))
             .open()
-            .context(format!("Failed to open serial port {}", port_path))?;
+            .map_err(|e| HardwareCommError::SerialOpen(format!("{}: {}", port_path, e)))?;
 
         info!("Successfully connected to hardware at {}", port_path);
 
@@ -199,79 +583,186 @@ impl HardwareComm {
         Ok(())
     }
 
-    fn parse_hardware_line(&self, line: &str) -> Option<OutMessage> {
-        if line.starts_with("\x01#") && line.contains("xC249") {
-            // Heartbeat
-            Some(OutMessage::Heartbeat)
-        } else if line.starts_with("\x01@") {
-            // Lap message: \x01@\t<sensor_id>\t...\t<racer_id>\t<race_time>\t...
-            let parts: Vec<&str> = line.split('\t').collect();
-            if parts.len() >= 6 {
-                match (parts.get(3), parts.get(1), parts.get(4)) {
-                    (Some(racer_id_str), Some(sensor_id_str), Some(race_time_str)) => {
-                        if let (Ok(racer_id), Ok(sensor_id), Ok(race_time)) = (
-                            racer_id_str.parse::<u32>(),
-                            sensor_id_str.parse::<u32>(),
-                            race_time_str.parse::<f64>(),
-                        ) {
-                            return Some(OutMessage::Lap {
-                                racer_id,
-                                sensor_id,
-                                race_time,
-                            });
-                        }
-                    }
-                    _ => {}
-                }
-            }
-            Some(OutMessage::Status {
-                message: format!("Malformed lap line: {}", line),
-            })
-        } else if line.starts_with("\x01$") {
-            // New message: \x01$\t<sensor_id>\t<raw_time>\t<flag1>\t<flag2>
-            let parts: Vec<&str> = line.split('\t').collect();
-            if parts.len() >= 5 {
-                // Just send as raw for now - we can add a NewMsg variant if needed
-                Some(OutMessage::Raw {
-                    line: line.to_string(),
-                })
-            } else {
-                Some(OutMessage::Status {
-                    message: format!("Malformed new_msg line: {}", line),
-                })
-            }
-        } else if !line.is_empty() {
-            Some(OutMessage::Raw {
-                line: line.to_string(),
-            })
+    /// Publishes a message to Redis immediately (durable stream entry plus
+    /// the live pub/sub notification), with no buffering. Fails if Redis is
+    /// unreachable; callers that need to survive an outage go through
+    /// `send_message` instead. The stream append and the pub/sub notify are
+    /// batched into a single pipelined round-trip rather than two.
+    async fn publish_now(&self, msg: &OutMessage) -> Result<(), HardwareCommError> {
+        let mut conn = self.conn.clone();
+
+        let json = serde_json::to_string(msg)
+            .map_err(|e| HardwareCommError::Parse(format!("failed to serialize message: {}", e)))?;
+
+        // Append to the durable event log first - this is the source of
+        // truth a consumer replays from after a restart or crash. Pub/sub
+        // below is only a best-effort live notification on top of it.
+        let mut fields: Vec<(&str, String)> =
+            vec![("type", out_message_type(msg).to_string()), ("payload", json.clone())];
+        if let OutMessage::Lap {
+            racer_id,
+            sensor_id,
+            race_time,
+        } = msg
+        {
+            fields.push(("racer_id", racer_id.to_string()));
+            fields.push(("sensor_id", sensor_id.to_string()));
+            fields.push(("race_time", race_time.to_string()));
+        }
+
+        redis::pipe()
+            .atomic()
+            .xadd_maxlen(
+                REDIS_EVENTS_STREAM,
+                redis::streams::StreamMaxlen::Approx(self.stream_maxlen),
+                "*",
+                &fields,
+            )
+            .ignore()
+            .publish(REDIS_OUT_CHANNEL, &json)
+            .ignore()
+            .query_async::<_, ()>(&mut conn)
+            .await
+            .map_err(Self::classify_redis_error)?;
+
+        Ok(())
+    }
+
+    /// Distinguishes a dropped/refused connection (transient, worth a
+    /// distinct status message so an operator knows to check Redis itself)
+    /// from a command-level publish failure on an otherwise-live connection.
+    fn classify_redis_error(e: redis::RedisError) -> HardwareCommError {
+        if e.is_io_error() || e.is_connection_dropped() || e.is_connection_refusal() {
+            HardwareCommError::RedisConnect(e.to_string())
         } else {
-            None
+            HardwareCommError::RedisPublish(e.to_string())
         }
     }
 
-    fn send_message(&self, msg: &OutMessage) -> Result<()> {
-        let mut conn = self
-            .redis_client
-            .get_connection()
-            .context("Failed to get Redis connection")?;
+    /// Idempotently creates the consumer group used by `stream_consumer_task`,
+    /// starting from the beginning of the stream (`0`) so a brand-new
+    /// consumer replays history instead of only seeing events published
+    /// after it joins. `MKSTREAM` covers the stream not existing yet.
+    async fn ensure_consumer_group(&self) -> Result<()> {
+        let mut conn = self.conn.clone();
+
+        let result: redis::RedisResult<()> = redis::cmd("XGROUP")
+            .arg("CREATE")
+            .arg(REDIS_EVENTS_STREAM)
+            .arg(REDIS_EVENTS_GROUP)
+            .arg("0")
+            .arg("MKSTREAM")
+            .query_async(&mut conn)
+            .await;
+
+        match result {
+            Ok(()) => Ok(()),
+            // The group already existing is the expected steady-state case.
+            Err(e) if e.code() == Some("BUSYGROUP") => Ok(()),
+            Err(e) => Err(e).context("Failed to create Redis stream consumer group"),
+        }
+    }
 
-        let json = serde_json::to_string(msg).context("Failed to serialize message")?;
+    /// Publishes a message to Redis, surviving transient Redis outages.
+    /// A publish failure enqueues `msg` on the bounded outbox instead of
+    /// returning an error, so the serial reader never blocks on a slow or
+    /// down Redis. Order is preserved: any already-buffered messages are
+    /// flushed first so a new message can't overtake older ones.
+    async fn send_message(&self, msg: &OutMessage) -> Result<()> {
+        self.try_flush_outbox().await;
+
+        if self.outbox_len() > 0 {
+            self.enqueue_outbound(msg.clone()).await;
+            return Ok(());
+        }
 
-        conn.publish::<_, _, ()>(REDIS_OUT_CHANNEL, json)
-            .context("Failed to publish to Redis")?;
+        if let Err(e) = self.publish_now(msg).await {
+            warn!("Publish to Redis failed, buffering message: {}", e);
+            self.enqueue_outbound(msg.clone()).await;
+        }
 
         Ok(())
     }
 
-    fn send_command(&self, cmd: &InMessage) -> Result<()> {
-        let mut conn = self
-            .redis_client
-            .get_connection()
-            .context("Failed to get Redis connection")?;
+    fn outbox_len(&self) -> usize {
+        self.outbox.lock().unwrap().len()
+    }
+
+    /// Drains the outbox in order for as long as publishing succeeds,
+    /// stopping at the first failure so the remaining buffered messages
+    /// stay queued for the next attempt.
+    ///
+    /// `outbox_flush_task` and every `send_message` caller can invoke this
+    /// concurrently, so each message is popped off the front *before* it is
+    /// published rather than merely peeked at: that way two callers can
+    /// never publish the same message twice, and a message is never
+    /// removed without having been published. A publish failure pushes the
+    /// message back onto the front so it isn't lost.
+    async fn try_flush_outbox(&self) {
+        loop {
+            let msg = {
+                let mut outbox = self.outbox.lock().unwrap();
+                outbox.pop_front()
+            };
+            let Some(msg) = msg else {
+                break;
+            };
+
+            match self.publish_now(&msg).await {
+                Ok(()) => {}
+                Err(_) => {
+                    self.outbox.lock().unwrap().push_front(msg);
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Enqueues a message that could not be published. Once the buffer is
+    /// full, low-priority messages (`Heartbeat`/`Debug`/`Raw`) are dropped
+    /// oldest-first to make room; `Lap` messages are never dropped, and the
+    /// buffer is allowed to grow past capacity rather than lose race data.
+    async fn enqueue_outbound(&self, msg: OutMessage) {
+        let mut outbox = self.outbox.lock().unwrap();
+        let mut shed = 0u64;
+
+        while outbox.len() >= OUTBOX_CAPACITY {
+            match outbox.iter().position(|m| {
+                matches!(
+                    m,
+                    OutMessage::Heartbeat | OutMessage::Debug { .. } | OutMessage::Raw { .. }
+                )
+            }) {
+                Some(pos) => {
+                    outbox.remove(pos);
+                    shed += 1;
+                }
+                None => break,
+            }
+        }
+
+        outbox.push_back(msg);
+        drop(outbox);
+
+        if shed > 0 {
+            let _ = self
+                .publish_now(&OutMessage::Status {
+                    message: format!(
+                        "Dropped {} low-priority buffered message(s) to make room in the outbox",
+                        shed
+                    ),
+                })
+                .await;
+        }
+    }
+
+    async fn send_command(&self, cmd: &InMessage) -> Result<()> {
+        let mut conn = self.conn.clone();
 
         let json = serde_json::to_string(cmd).context("Failed to serialize command")?;
 
         conn.publish::<_, _, ()>(REDIS_IN_CHANNEL, json)
+            .await
             .context("Failed to publish command to Redis")?;
 
         Ok(())
@@ -280,75 +771,256 @@ impl HardwareComm {
 
 // Background task to listen for messages from Redis
 async fn redis_listener_task(hw: Arc<HardwareComm>, app: Arc<Mutex<App>>) -> Result<()> {
-    // Run blocking Redis operations in a separate thread
-    tokio::task::spawn_blocking(move || {
-        let mut conn = match hw.redis_client.get_connection() {
-            Ok(c) => c,
+    let mut pubsub = hw
+        .redis_client
+        .get_async_pubsub()
+        .await
+        .context("Failed to open Redis pubsub connection")?;
+
+    pubsub
+        .subscribe(REDIS_OUT_CHANNEL)
+        .await
+        .context("Failed to subscribe to Redis channel")?;
+
+    let mut messages = pubsub.on_message();
+
+    loop {
+        let should_quit = {
+            let app = app.lock().await;
+            app.should_quit
+        };
+
+        if should_quit {
+            info!("Redis listener task exiting");
+            break;
+        }
+
+        // Poll with a timeout so we notice should_quit even with no traffic.
+        let msg = match tokio::time::timeout(Duration::from_millis(100), messages.next()).await {
+            Ok(Some(m)) => m,
+            Ok(None) => break, // connection closed
+            Err(_) => continue, // timed out, loop back to the should_quit check
+        };
+
+        let payload: String = match msg.get_payload() {
+            Ok(p) => p,
             Err(e) => {
-                error!("Failed to get Redis connection for pubsub: {}", e);
-                return;
+                error!("Failed to get payload from message: {}", e);
+                continue;
             }
         };
 
-        let mut pubsub = conn.as_pubsub();
+        if let Ok(out_msg) = serde_json::from_str::<OutMessage>(&payload) {
+            let mut app = app.lock().await;
+            let formatted = app.format_out_message(&out_msg);
+            app.add_message(formatted);
+        }
+    }
+
+    Ok(())
+}
+
+/// One lap event read off `REDIS_EVENTS_STREAM` via the consumer group,
+/// paired with the means to acknowledge it. `stream_consumer_task` only
+/// `XACK`s the underlying entry once the downstream consumer calls
+/// [`LapEvent::ack`], so a crash between the read and that call leaves the
+/// entry pending and it replays on the next startup instead of being lost.
+/// Dropping a `LapEvent` without acking it has the same effect: the entry
+/// just stays pending.
+#[derive(Debug)]
+struct LapEvent {
+    racer_id: u32,
+    sensor_id: u32,
+    race_time: f64,
+    ack: tokio::sync::oneshot::Sender<()>,
+}
+
+impl LapEvent {
+    /// Acknowledges the event, letting `stream_consumer_task` issue the
+    /// `XACK` for its stream entry. Call this only once the event has
+    /// actually been processed downstream.
+    fn ack(self) {
+        let _ = self.ack.send(());
+    }
+}
+
+/// Background task giving at-least-once delivery of lap events, as an
+/// alternative to the best-effort pub/sub relay in `redis_listener_task`.
+/// On startup (and after any reconnect) it first drains `REDIS_EVENTS_CONSUMER`'s
+/// own pending entries (`XREADGROUP ... 0`) before switching to new entries
+/// (`>`). Because the consumer name is fixed rather than derived from the
+/// process id, a restart actually sees the prior run's unacknowledged
+/// entries under that same name and replays them, instead of abandoning
+/// them in the old consumer's PEL. Decoded events are forwarded on `tx`;
+/// send it into `tokio_stream::wrappers::ReceiverStream` via
+/// `lap_event_stream` below for ergonomic consumption downstream.
+///
+/// The `XACK` for each entry is not issued here: it's deferred until the
+/// downstream consumer calls `LapEvent::ack`, via a oneshot channel attached
+/// to the event. That keeps the guarantee genuinely at-least-once — a crash
+/// after the event is merely handed to `tx` (or is sitting in the bounded
+/// channel waiting to be read) leaves the entry pending, so it's replayed
+/// rather than lost. Entries this task can't even decode are acked
+/// immediately, since no amount of replay will make them decodable.
+async fn stream_consumer_task(
+    hw: Arc<HardwareComm>,
+    app: Arc<Mutex<App>>,
+    tx: tokio::sync::mpsc::Sender<LapEvent>,
+) -> Result<()> {
+    hw.ensure_consumer_group().await?;
 
-        // Set read timeout so we don't block forever
-        pubsub
-            .set_read_timeout(Some(std::time::Duration::from_millis(100)))
-            .ok();
+    let mut conn = hw.conn.clone();
+    let mut replaying_pending = true;
 
-        if let Err(e) = pubsub.subscribe(REDIS_OUT_CHANNEL) {
-            error!("Failed to subscribe to Redis channel: {}", e);
-            return;
+    loop {
+        let should_quit = {
+            let app = app.lock().await;
+            app.should_quit
+        };
+        if should_quit {
+            info!("Stream consumer task exiting");
+            break;
         }
 
-        loop {
-            // Check if we should quit
-            let rt = tokio::runtime::Handle::current();
-            let should_quit = rt.block_on(async {
-                let app = app.lock().await;
-                app.should_quit
-            });
+        let start_id = if replaying_pending { "0" } else { ">" };
+        let opts = redis::streams::StreamReadOptions::default()
+            .group(REDIS_EVENTS_GROUP, REDIS_EVENTS_CONSUMER)
+            .count(50);
 
-            if should_quit {
-                info!("Redis listener task exiting");
-                break;
+        let reply: redis::streams::StreamReadReply = match conn
+            .xread_options(&[REDIS_EVENTS_STREAM], &[start_id], &opts)
+            .await
+        {
+            Ok(r) => r,
+            Err(e) => {
+                error!("XREADGROUP failed: {}", e);
+                replaying_pending = true;
+                tokio::time::sleep(Duration::from_millis(500)).await;
+                continue;
             }
+        };
 
-            // Get message (will timeout after 100ms)
-            let msg = match pubsub.get_message() {
-                Ok(m) => m,
-                Err(e) => {
-                    // Timeout is expected, just continue
-                    // Redis returns IoError for timeouts
-                    if e.is_io_error() {
-                        continue;
+        let mut saw_entries = false;
+        for stream_key in &reply.keys {
+            for entry in &stream_key.ids {
+                saw_entries = true;
+
+                if let (Some(racer_id), Some(sensor_id), Some(race_time)) = (
+                    entry.get::<u32>("racer_id"),
+                    entry.get::<u32>("sensor_id"),
+                    entry.get::<f64>("race_time"),
+                ) {
+                    let (ack_tx, ack_rx) = tokio::sync::oneshot::channel();
+                    let sent = tx
+                        .send(LapEvent {
+                            racer_id,
+                            sensor_id,
+                            race_time,
+                            ack: ack_tx,
+                        })
+                        .await
+                        .is_ok();
+
+                    if sent {
+                        // Ack once the downstream consumer actually processes
+                        // the event, not when it's merely handed off here.
+                        // Runs independently so one slow consumer can't stall
+                        // the read loop for every other entry in the batch.
+                        let mut ack_conn = conn.clone();
+                        let entry_id = entry.id.clone();
+                        tokio::spawn(async move {
+                            if ack_rx.await.is_err() {
+                                // Event was dropped without being acked; leave
+                                // it pending so it's replayed on restart.
+                                return;
+                            }
+                            if let Err(e) = redis::cmd("XACK")
+                                .arg(REDIS_EVENTS_STREAM)
+                                .arg(REDIS_EVENTS_GROUP)
+                                .arg(&entry_id)
+                                .query_async::<_, i64>(&mut ack_conn)
+                                .await
+                            {
+                                error!("XACK failed for entry {}: {}", entry_id, e);
+                            }
+                        });
+                    }
+                } else {
+                    // Undecodable entry: no amount of replay fixes it, so ack
+                    // it now rather than leaving it pending forever.
+                    if let Err(e) = redis::cmd("XACK")
+                        .arg(REDIS_EVENTS_STREAM)
+                        .arg(REDIS_EVENTS_GROUP)
+                        .arg(&entry.id)
+                        .query_async::<_, i64>(&mut conn)
+                        .await
+                    {
+                        error!("XACK failed for entry {}: {}", entry.id, e);
                     }
-                    error!("Failed to get message from Redis: {}", e);
-                    continue;
                 }
-            };
+            }
+        }
 
-            let payload: String = match msg.get_payload() {
-                Ok(p) => p,
-                Err(e) => {
-                    error!("Failed to get payload from message: {}", e);
-                    continue;
-                }
-            };
+        if replaying_pending && !saw_entries {
+            // No more backlog for this consumer - switch to live entries.
+            replaying_pending = false;
+            continue;
+        }
 
-            if let Ok(out_msg) = serde_json::from_str::<OutMessage>(&payload) {
-                // Use a blocking runtime to lock the mutex
-                let rt = tokio::runtime::Handle::current();
-                rt.block_on(async {
-                    let mut app = app.lock().await;
-                    let formatted = app.format_out_message(&out_msg);
-                    app.add_message(formatted);
-                });
-            }
+        if !saw_entries {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    }
+
+    Ok(())
+}
+
+/// Spawns `stream_consumer_task` and returns the lap events it forwards as
+/// a `Stream`, so downstream rendering/aggregation code can `.next().await`
+/// rather than manage the channel directly.
+fn lap_event_stream(
+    hw: Arc<HardwareComm>,
+    app: Arc<Mutex<App>>,
+) -> impl tokio_stream::Stream<Item = LapEvent> {
+    let (tx, rx) = tokio::sync::mpsc::channel(128);
+    tokio::spawn(async move {
+        if let Err(e) = stream_consumer_task(hw, app, tx).await {
+            error!("Stream consumer task error: {}", e);
+        }
+    });
+    tokio_stream::wrappers::ReceiverStream::new(rx)
+}
+
+// Background task that retries buffered messages once Redis recovers from
+// an outage, with exponential backoff while the outbox stays non-empty.
+async fn outbox_flush_task(hw: Arc<HardwareComm>, app: Arc<Mutex<App>>) -> Result<()> {
+    const MIN_BACKOFF: Duration = Duration::from_millis(200);
+    const MAX_BACKOFF: Duration = Duration::from_secs(10);
+
+    let mut backoff = MIN_BACKOFF;
+
+    loop {
+        let should_quit = {
+            let app = app.lock().await;
+            app.should_quit
+        };
+
+        if should_quit {
+            info!("Outbox flush task exiting");
+            break;
         }
-    })
-    .await?;
+
+        hw.try_flush_outbox().await;
+        let remaining = hw.outbox_len();
+
+        if remaining > 0 {
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        } else {
+            backoff = MIN_BACKOFF;
+            tokio::time::sleep(MIN_BACKOFF).await;
+        }
+    }
 
     Ok(())
 }
@@ -360,7 +1032,8 @@ async fn simulation_task(hw: Arc<HardwareComm>, app: Arc<Mutex<App>>) -> Result<
     // Send initial status
     hw.send_message(&OutMessage::Status {
         message: "Running in simulation mode".to_string(),
-    })?;
+    })
+    .await?;
 
     let mut last_heartbeat = Instant::now();
 
@@ -369,7 +1042,7 @@ async fn simulation_task(hw: Arc<HardwareComm>, app: Arc<Mutex<App>>) -> Result<
 
         // Send heartbeat every 2 seconds
         if last_heartbeat.elapsed() >= Duration::from_secs(2) {
-            hw.send_message(&OutMessage::Heartbeat)?;
+            hw.send_message(&OutMessage::Heartbeat).await?;
             last_heartbeat = Instant::now();
         }
 
@@ -391,112 +1064,162 @@ async fn simulation_task(hw: Arc<HardwareComm>, app: Arc<Mutex<App>>) -> Result<
 async fn hardware_task(hw: Arc<HardwareComm>, app: Arc<Mutex<App>>) -> Result<()> {
     info!("Starting hardware task");
 
-    // Run blocking serial operations in a separate thread
-    tokio::task::spawn_blocking(move || {
-        // Open serial port
-        let mut port = match hw.open_serial_connection() {
-            Ok(p) => p,
-            Err(e) => {
-                error!("Failed to open serial connection: {}", e);
-                let _ = hw.send_message(&OutMessage::Status {
-                    message: format!("Lap tracking hardware not found: {}", e),
-                });
-                return;
+    // Run blocking serial operations in a separate thread. This is a
+    // supervised loop: a recoverable `HardwareCommError` (port open/read
+    // failure, disconnect) emits a Status, backs off, and reopens the port
+    // from scratch rather than returning and leaving the app permanently
+    // dead until restart. A non-recoverable error ends this task and is
+    // returned from `hardware_task`; the rest of the app keeps running.
+    tokio::task::spawn_blocking(move || -> Result<(), HardwareCommError> {
+        const MIN_BACKOFF: Duration = Duration::from_millis(500);
+        const MAX_BACKOFF: Duration = Duration::from_secs(30);
+        let mut backoff = MIN_BACKOFF;
+        let rt = tokio::runtime::Handle::current();
+        // Redis publishes are async now (query_async over the multiplexed
+        // connection); bridge them back into this blocking thread rather
+        // than threading async through the serial read loop.
+        let send = |msg: &OutMessage| rt.block_on(hw.send_message(msg));
+
+        'reconnect: loop {
+            let should_quit = rt.block_on(async { app.lock().await.should_quit });
+            if should_quit {
+                info!("Hardware task exiting");
+                return Ok(());
             }
-        };
 
-        // Send initial status
-        let status_msg = format!(
-            "Hardware connected and initialized at {}",
-            hw.serial_port_path
-                .as_ref()
-                .unwrap_or(&"unknown".to_string())
-        );
-        if let Err(e) = hw.send_message(&OutMessage::Status {
-            message: status_msg,
-        }) {
-            error!("Failed to send status: {}", e);
-        }
+            // Open serial port
+            let mut port = match hw.open_serial_connection() {
+                Ok(p) => p,
+                Err(e) => {
+                    error!("{}", e);
+                    let _ = send(&OutMessage::Status {
+                        message: e.to_string(),
+                    });
+                    if !e.is_recoverable() {
+                        return Err(e);
+                    }
+                    std::thread::sleep(backoff);
+                    backoff = (backoff * 2).min(MAX_BACKOFF);
+                    continue 'reconnect;
+                }
+            };
+            backoff = MIN_BACKOFF;
+
+            // Send initial status
+            let status_msg = format!(
+                "Hardware connected and initialized at {}",
+                hw.serial_port_path
+                    .as_ref()
+                    .unwrap_or(&"unknown".to_string())
+            );
+            if let Err(e) = send(&OutMessage::Status {
+                message: status_msg,
+            }) {
+                error!("Failed to send status: {}", e);
+            }
 
-        // Send reset commands
-        if let Err(e) = hw.send_reset_commands(&mut port) {
-            error!("Failed to send reset commands: {}", e);
-            let _ = hw.send_message(&OutMessage::Status {
-                message: format!("Error sending reset commands: {}", e),
-            });
-        }
+            // Send reset commands
+            if let Err(e) = hw.send_reset_commands(&mut port) {
+                error!("Failed to send reset commands: {}", e);
+                let _ = send(&OutMessage::Status {
+                    message: format!("Error sending reset commands: {}", e),
+                });
+            }
+            // A reset requested while the port was down was already covered
+            // by the reconnect above; don't send it again immediately.
+            hw.take_reset_request();
 
-        // Create buffered reader for line reading
-        let mut reader = BufReader::new(port);
-        let mut last_heartbeat = Instant::now();
+            let mut frame_buf = SerialFramer::new();
+            let mut read_buf = [0u8; FRAME_BUFFER_SIZE];
+            let mut last_heartbeat = Instant::now();
 
-        loop {
-            // Check if we should quit
-            let rt = tokio::runtime::Handle::current();
-            let should_quit = rt.block_on(async {
-                let app = app.lock().await;
-                app.should_quit
-            });
+            loop {
+                // Check if we should quit
+                let should_quit = rt.block_on(async { app.lock().await.should_quit });
 
-            if should_quit {
-                info!("Hardware task exiting");
-                break;
-            }
+                if should_quit {
+                    info!("Hardware task exiting");
+                    return Ok(());
+                }
 
-            // Read line from serial (with timeout)
-            let mut line_buf = String::new();
-            match reader.read_line(&mut line_buf) {
-                Ok(0) => {
-                    // No data, continue
-                    std::thread::sleep(Duration::from_millis(50));
-                    continue;
+                if hw.take_reset_request() {
+                    info!("Sending requested reset commands to hardware");
+                    if let Err(e) = hw.send_reset_commands(&mut port) {
+                        error!("Failed to send reset commands: {}", e);
+                        let _ = send(&OutMessage::Status {
+                            message: format!("Error sending reset commands: {}", e),
+                        });
+                    }
                 }
-                Ok(_) => {
-                    let line = line_buf.trim();
-
-                    // Parse and send message
-                    if let Some(msg) = hw.parse_hardware_line(line) {
-                        // Update heartbeat time if we got a heartbeat
-                        if matches!(msg, OutMessage::Heartbeat) {
-                            last_heartbeat = Instant::now();
+
+                // Read whatever bytes are available (with timeout) into the
+                // ring buffer, then hand every complete frame to the parser.
+                match read_and_decode(&mut port, &mut frame_buf, &mut read_buf) {
+                    Ok(DecodedRead {
+                        messages,
+                        overflow: None,
+                    }) if messages.is_empty() => {
+                        // No data, continue
+                        std::thread::sleep(Duration::from_millis(50));
+                        continue;
+                    }
+                    Ok(DecodedRead { messages, overflow }) => {
+                        if let Some(reason) = overflow {
+                            let _ = send(&OutMessage::Status {
+                                message: reason.to_string(),
+                            });
                         }
 
-                        if let Err(e) = hw.send_message(&msg) {
-                            error!("Failed to send message: {}", e);
+                        for msg in messages {
+                            // Update heartbeat time if we got a heartbeat
+                            if matches!(msg, OutMessage::Heartbeat) {
+                                last_heartbeat = Instant::now();
+                            }
+
+                            if let Err(e) = send(&msg) {
+                                error!("Failed to send message: {}", e);
+                            }
                         }
                     }
-                }
-                Err(e) => {
-                    // Check for timeout (expected)
-                    if e.kind() == std::io::ErrorKind::TimedOut {
-                        continue;
+                    Err(e) => {
+                        // Check for timeout (expected)
+                        if e.kind() == std::io::ErrorKind::TimedOut {
+                            continue;
+                        }
+                        let err = HardwareCommError::SerialRead(e.to_string());
+                        error!("{}", err);
+                        let _ = send(&OutMessage::Status {
+                            message: err.to_string(),
+                        });
+                        // A read error past a timeout means the port itself
+                        // is gone (unplugged, USB reset) - reopen it rather
+                        // than spinning on the same broken handle.
+                        std::thread::sleep(backoff);
+                        backoff = (backoff * 2).min(MAX_BACKOFF);
+                        continue 'reconnect;
                     }
-                    error!("Error reading from serial: {}", e);
-                    let _ = hw.send_message(&OutMessage::Status {
-                        message: format!("Error reading serial: {}", e),
+                }
+
+                // Check for heartbeat timeout (10 seconds)
+                if last_heartbeat.elapsed() > Duration::from_secs(10) {
+                    warn!("Heartbeat lost");
+                    let _ = send(&OutMessage::Status {
+                        message: "Heartbeat lost".to_string(),
                     });
+                    last_heartbeat = Instant::now(); // Reset to avoid spam
                 }
-            }
 
-            // Check for heartbeat timeout (10 seconds)
-            if last_heartbeat.elapsed() > Duration::from_secs(10) {
-                warn!("Heartbeat lost");
-                let _ = hw.send_message(&OutMessage::Status {
-                    message: "Heartbeat lost".to_string(),
-                });
-                last_heartbeat = Instant::now(); // Reset to avoid spam
+                std::thread::sleep(Duration::from_millis(50));
             }
-
-            std::thread::sleep(Duration::from_millis(50));
         }
     })
-    .await?;
+    .await??;
 
     Ok(())
 }
 
 // Handle user input
-fn handle_input(app: &mut App, hw: &HardwareComm, key: KeyCode) -> Result<()> {
+async fn handle_input(app: &mut App, hw: &HardwareComm, key: KeyCode) -> Result<()> {
     match key {
         KeyCode::Char('q') | KeyCode::Char('Q') => {
             app.should_quit = true;
@@ -507,7 +1230,8 @@ fn handle_input(app: &mut App, hw: &HardwareComm, key: KeyCode) -> Result<()> {
             // In simulation mode, send message directly without going through Redis command channel
             hw.send_message(&OutMessage::Status {
                 message: "Simulation race started".to_string(),
-            })?;
+            })
+            .await?;
             info!("Simulation race started");
         }
         KeyCode::Char('p') | KeyCode::Char('P') if app.simulation_mode => {
@@ -516,7 +1240,8 @@ fn handle_input(app: &mut App, hw: &HardwareComm, key: KeyCode) -> Result<()> {
             // In simulation mode, send message directly without going through Redis command channel
             hw.send_message(&OutMessage::Status {
                 message: "Simulation race stopped".to_string(),
-            })?;
+            })
+            .await?;
             info!("Simulation race stopped");
         }
         KeyCode::Char(c @ '1'..='4') if app.simulation_mode => {
@@ -532,7 +1257,8 @@ fn handle_input(app: &mut App, hw: &HardwareComm, key: KeyCode) -> Result<()> {
                 racer_id,
                 sensor_id: 1,
                 race_time,
-            })?;
+            })
+            .await?;
             info!("Simulated lap for racer {}", racer_id);
         }
         _ => {}
@@ -543,106 +1269,140 @@ fn handle_input(app: &mut App, hw: &HardwareComm, key: KeyCode) -> Result<()> {
 
 // Background task to handle Redis commands (simulation mode)
 async fn command_handler_task(hw: Arc<HardwareComm>, app: Arc<Mutex<App>>) -> Result<()> {
-    // Run blocking Redis operations in a separate thread
-    tokio::task::spawn_blocking(move || {
-        let mut conn = match hw.redis_client.get_connection() {
-            Ok(c) => c,
-            Err(e) => {
-                error!("Failed to get Redis connection for command handler: {}", e);
-                return;
-            }
-        };
+    let mut pubsub = hw
+        .redis_client
+        .get_async_pubsub()
+        .await
+        .context("Failed to open Redis pubsub connection for command handler")?;
+
+    pubsub
+        .subscribe(REDIS_IN_CHANNEL)
+        .await
+        .context("Failed to subscribe to command channel")?;
 
-        let mut pubsub = conn.as_pubsub();
+    let mut messages = pubsub.on_message();
 
-        // Set read timeout so we don't block forever
-        pubsub
-            .set_read_timeout(Some(std::time::Duration::from_millis(100)))
-            .ok();
+    loop {
+        let should_quit = {
+            let app = app.lock().await;
+            app.should_quit
+        };
 
-        if let Err(e) = pubsub.subscribe(REDIS_IN_CHANNEL) {
-            error!("Failed to subscribe to command channel: {}", e);
-            return;
+        if should_quit {
+            info!("Command handler task exiting");
+            break;
         }
 
-        loop {
-            // Check if we should quit
-            let rt = tokio::runtime::Handle::current();
-            let should_quit = rt.block_on(async {
-                let app = app.lock().await;
-                app.should_quit
-            });
+        // Poll with a timeout so we notice should_quit even with no traffic.
+        let msg = match tokio::time::timeout(Duration::from_millis(100), messages.next()).await {
+            Ok(Some(m)) => m,
+            Ok(None) => break, // connection closed
+            Err(_) => continue, // timed out, loop back to the should_quit check
+        };
 
-            if should_quit {
-                info!("Command handler task exiting");
-                break;
+        let payload: String = match msg.get_payload() {
+            Ok(p) => p,
+            Err(e) => {
+                error!("Failed to get command payload: {}", e);
+                continue;
             }
+        };
 
-            // Get message (will timeout after 100ms)
-            let msg = match pubsub.get_message() {
-                Ok(m) => m,
-                Err(e) => {
-                    // Timeout is expected, just continue
-                    // Redis returns IoError for timeouts
-                    if e.is_io_error() {
-                        continue;
-                    }
-                    error!("Failed to get command message: {}", e);
-                    continue;
-                }
-            };
+        let in_msg = match serde_json::from_str::<InMessage>(&payload) {
+            Ok(m) => m,
+            Err(e) => {
+                error!("Failed to parse command payload: {}", e);
+                continue;
+            }
+        };
 
-            let payload: String = match msg.get_payload() {
-                Ok(p) => p,
-                Err(e) => {
-                    error!("Failed to get command payload: {}", e);
-                    continue;
-                }
-            };
+        let InMessage::Command { ref command, .. } = in_msg;
+        let command_str = command.clone();
+
+        let Some(cmd) = HardwareCommand::parse(in_msg) else {
+            error!("Unknown command: {}", command_str);
+            continue;
+        };
 
-            if let Ok(in_msg) = serde_json::from_str::<InMessage>(&payload) {
-                match in_msg {
-                    InMessage::Command {
-                        command,
+        match cmd {
+            HardwareCommand::StartRace => {
+                {
+                    let mut app = app.lock().await;
+                    app.race_active = true;
+                    app.race_start_time = Some(Instant::now());
+                }
+                if let Err(e) = hw
+                    .send_message(&OutMessage::Status {
+                        message: "Simulation race started".to_string(),
+                    })
+                    .await
+                {
+                    error!("Failed to send status: {}", e);
+                }
+                info!("Simulation race started");
+            }
+            HardwareCommand::StopRace => {
+                {
+                    let mut app = app.lock().await;
+                    app.race_active = false;
+                    app.race_start_time = None;
+                }
+                if let Err(e) = hw
+                    .send_message(&OutMessage::Status {
+                        message: "Simulation race stopped".to_string(),
+                    })
+                    .await
+                {
+                    error!("Failed to send status: {}", e);
+                }
+                info!("Simulation race stopped");
+            }
+            HardwareCommand::SimulateLap {
+                racer_id,
+                sensor_id,
+                race_time,
+            } => {
+                if let Err(e) = hw
+                    .send_message(&OutMessage::Lap {
                         racer_id,
                         sensor_id,
                         race_time,
-                    } => match command.as_str() {
-                        "start_race" => {
-                            if let Err(e) = hw.send_message(&OutMessage::Status {
-                                message: "Simulation race started".to_string(),
-                            }) {
-                                error!("Failed to send status: {}", e);
-                            }
-                            info!("Simulation race started");
-                        }
-                        "stop_race" => {
-                            if let Err(e) = hw.send_message(&OutMessage::Status {
-                                message: "Simulation race stopped".to_string(),
-                            }) {
-                                error!("Failed to send status: {}", e);
-                            }
-                            info!("Simulation race stopped");
-                        }
-                        "simulate_lap" => {
-                            if let Err(e) = hw.send_message(&OutMessage::Lap {
-                                racer_id: racer_id.unwrap_or(1),
-                                sensor_id: sensor_id.unwrap_or(1),
-                                race_time: race_time.unwrap_or(0.0),
-                            }) {
-                                error!("Failed to send lap message: {}", e);
-                            }
-                            info!("Simulated lap for racer {}", racer_id.unwrap_or(1));
-                        }
-                        _ => {
-                            error!("Unknown command: {}", command);
-                        }
-                    },
+                    })
+                    .await
+                {
+                    error!("Failed to send lap message: {}", e);
                 }
+                info!("Simulated lap for racer {}", racer_id);
+            }
+            HardwareCommand::Reset => {
+                hw.request_reset();
+                if let Err(e) = hw
+                    .send_message(&OutMessage::Status {
+                        message: "Hardware reset requested".to_string(),
+                    })
+                    .await
+                {
+                    error!("Failed to send status: {}", e);
+                }
+                info!("Hardware reset requested via command channel");
+            }
+            HardwareCommand::SetBaudrate(baudrate) => {
+                hw.set_baudrate(baudrate);
+                if let Err(e) = hw
+                    .send_message(&OutMessage::Status {
+                        message: format!(
+                            "Baudrate set to {}, effective on next reconnect",
+                            baudrate
+                        ),
+                    })
+                    .await
+                {
+                    error!("Failed to send status: {}", e);
+                }
+                info!("Baudrate set to {} via command channel", baudrate);
             }
         }
-    })
-    .await?;
+    }
 
     Ok(())
 }
@@ -752,13 +1512,24 @@ async fn main() -> Result<()> {
     let args: Vec<String> = std::env::args().collect();
     let simulation_mode = args.contains(&"--sim".to_string()) || args.contains(&"-s".to_string());
 
-    // Parse redis socket path (--redis-socket <path>)
-    let redis_socket_path = if let Some(pos) = args.iter().position(|a| a == "--redis-socket") {
+    // Parse Redis connection. `--redis-url` takes a full connection URL
+    // (unix://, redis://host:port, or rediss://host:port for TLS) and takes
+    // precedence over the legacy `--redis-socket <path>`, kept for
+    // backward compatibility with a co-located socket.
+    let redis_url = if let Some(pos) = args.iter().position(|a| a == "--redis-url") {
         args.get(pos + 1)
-            .map(|s| s.as_str())
-            .unwrap_or(DEFAULT_REDIS_SOCKET_PATH)
+            .cloned()
+            .context("--redis-url requires a value")?
     } else {
-        DEFAULT_REDIS_SOCKET_PATH
+        let redis_socket_path =
+            if let Some(pos) = args.iter().position(|a| a == "--redis-socket") {
+                args.get(pos + 1)
+                    .map(|s| s.as_str())
+                    .unwrap_or(DEFAULT_REDIS_SOCKET_PATH)
+            } else {
+                DEFAULT_REDIS_SOCKET_PATH
+            };
+        unix_socket_url(redis_socket_path)?
     };
 
     // Parse serial port (--serial-port <path>)
@@ -781,27 +1552,43 @@ async fn main() -> Result<()> {
         9600
     };
 
+    // Parse event stream trim length (--stream-maxlen <n>)
+    let stream_maxlen = if let Some(pos) = args.iter().position(|a| a == "--stream-maxlen") {
+        args.get(pos + 1)
+            .and_then(|s| s.parse::<usize>().ok())
+            .unwrap_or(DEFAULT_STREAM_MAXLEN)
+    } else {
+        DEFAULT_STREAM_MAXLEN
+    };
+
     // Create app state
     let app = Arc::new(Mutex::new(App::new(simulation_mode)));
 
     // Create hardware comm
-    let hw = Arc::new(HardwareComm::new(
-        simulation_mode,
-        redis_socket_path,
-        serial_port,
-        baudrate,
-    )?);
+    let hw = Arc::new(
+        HardwareComm::new(
+            simulation_mode,
+            &redis_url,
+            serial_port,
+            baudrate,
+            stream_maxlen,
+        )
+        .await?,
+    );
 
     // Test Redis connection
-    match hw.redis_client.get_connection() {
-        Ok(mut conn) => {
-            redis::cmd("PING").query::<String>(&mut conn)?;
+    let mut ping_conn = hw.conn.clone();
+    match redis::cmd("PING")
+        .query_async::<_, String>(&mut ping_conn)
+        .await
+    {
+        Ok(_) => {
             info!("Redis connection successful");
         }
         Err(e) => {
             error!("Failed to connect to Redis: {}", e);
             eprintln!("Failed to connect to Redis: {}", e);
-            eprintln!("Make sure Redis is running with: redis-server --unixsocket ./redis.sock");
+            eprintln!("Make sure Redis is reachable at {} (see --redis-url / --redis-socket)", redis_url);
             return Err(e.into());
         }
     }
@@ -815,6 +1602,28 @@ async fn main() -> Result<()> {
         }
     });
 
+    let app_clone = app.clone();
+    let hw_clone = hw.clone();
+    tokio::spawn(async move {
+        if let Err(e) = outbox_flush_task(hw_clone, app_clone).await {
+            error!("Outbox flush task error: {}", e);
+        }
+    });
+
+    // Durable, at-least-once lap feed via the stream consumer group -
+    // independent of the best-effort pub/sub relay above, for consumers
+    // (e.g. a scoreboard) that need to catch up on laps missed while offline.
+    let mut lap_events = Box::pin(lap_event_stream(hw.clone(), app.clone()));
+    tokio::spawn(async move {
+        while let Some(event) = lap_events.next().await {
+            info!(
+                "Durable lap event: racer {} sensor {} at {:.3}s",
+                event.racer_id, event.sensor_id, event.race_time
+            );
+            event.ack();
+        }
+    });
+
     if simulation_mode {
         let app_clone = app.clone();
         let hw_clone = hw.clone();
@@ -889,7 +1698,7 @@ async fn run_app(
         if event::poll(Duration::from_millis(100))? {
             if let Event::Key(key) = event::read()? {
                 let mut app_lock = app.lock().await;
-                handle_input(&mut app_lock, &hw, key.code)?;
+                handle_input(&mut app_lock, &hw, key.code).await?;
 
                 if app_lock.should_quit {
                     break;
@@ -900,3 +1709,283 @@ async fn run_app(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    /// A `ByteSource` that replays a fixed capture of hardware bytes, sliced
+    /// into chunks at whatever boundaries the test chooses - mid-frame,
+    /// mid-number, or splitting a multi-byte sequence - so the decoder can
+    /// be checked against adversarial read granularity.
+    struct MockSource {
+        chunks: VecDeque<Vec<u8>>,
+    }
+
+    impl MockSource {
+        fn new(chunks: Vec<Vec<u8>>) -> Self {
+            Self {
+                chunks: chunks.into(),
+            }
+        }
+    }
+
+    impl ByteSource for MockSource {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.chunks.pop_front() {
+                Some(chunk) => {
+                    let n = chunk.len().min(buf.len());
+                    buf[..n].copy_from_slice(&chunk[..n]);
+                    Ok(n)
+                }
+                None => Ok(0),
+            }
+        }
+    }
+
+    const HEARTBEAT: &[u8] = b"\x01#xC249\r\n";
+    const LAP: &[u8] = b"\x01@\t1\t0\t3\t12.345\t0\r\n";
+    const NEW_MSG: &[u8] = b"\x01$\t1\t100\t0\t0\r\n";
+
+    fn decode_all(chunks: Vec<Vec<u8>>) -> Vec<OutMessage> {
+        let mut source = MockSource::new(chunks);
+        let mut frames = SerialFramer::new();
+        let mut scratch = [0u8; FRAME_BUFFER_SIZE];
+        let mut out = Vec::new();
+        while !source.chunks.is_empty() {
+            let decoded = read_and_decode(&mut source, &mut frames, &mut scratch).unwrap();
+            assert!(decoded.overflow.is_none(), "unexpected buffer overflow");
+            out.extend(decoded.messages);
+        }
+        out
+    }
+
+    #[test]
+    fn whole_frames_in_one_read() {
+        let messages = decode_all(vec![[HEARTBEAT, LAP, NEW_MSG].concat()]);
+        assert_eq!(messages.len(), 3);
+        assert!(matches!(messages[0], OutMessage::Heartbeat));
+        assert!(matches!(messages[1], OutMessage::Lap { .. }));
+        assert!(matches!(messages[2], OutMessage::Raw { .. }));
+    }
+
+    #[test]
+    fn frame_split_mid_frame() {
+        let combined = [HEARTBEAT, LAP].concat();
+        let split = combined.len() / 2;
+        let messages = decode_all(vec![
+            combined[..split].to_vec(),
+            combined[split..].to_vec(),
+        ]);
+        assert_eq!(messages.len(), 2);
+        assert!(matches!(messages[0], OutMessage::Heartbeat));
+        assert!(matches!(messages[1], OutMessage::Lap { .. }));
+    }
+
+    #[test]
+    fn frame_split_mid_number() {
+        // Split partway through the race_time field.
+        let split = LAP.len() - 7;
+        let messages = decode_all(vec![LAP[..split].to_vec(), LAP[split..].to_vec()]);
+        assert_eq!(messages.len(), 1);
+        match &messages[0] {
+            OutMessage::Lap { race_time, .. } => {
+                assert!((*race_time - 12.345).abs() < 1e-9)
+            }
+            other => panic!("expected Lap, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn byte_by_byte_reads_reassemble_every_frame() {
+        let combined = [HEARTBEAT, LAP, NEW_MSG].concat();
+        let chunks = combined.iter().map(|&b| vec![b]).collect();
+        let messages = decode_all(chunks);
+        assert_eq!(messages.len(), 3);
+    }
+
+    #[test]
+    fn invalid_utf8_malformed_new_msg_yields_status() {
+        let mut frame = vec![0x01, b'$'];
+        frame.extend_from_slice(&[0xFF, 0xFE, b'\t', b'1']);
+        frame.extend_from_slice(b"\r\n");
+        let messages = decode_all(vec![frame]);
+        assert_eq!(messages.len(), 1);
+        assert!(matches!(messages[0], OutMessage::Status { .. }));
+    }
+
+    #[test]
+    fn serial_framer_yields_frames_without_a_byte_source() {
+        // SerialFramer::push_bytes is exercised directly here, with no
+        // ByteSource/read_and_decode involved, so it's reusable anywhere a
+        // byte stream needs framing.
+        let mut framer = SerialFramer::new();
+        let combined = [HEARTBEAT, LAP].concat();
+
+        let frames: Vec<Frame> = framer.push_bytes(&combined).collect();
+
+        assert_eq!(frames.len(), 2);
+        assert!(framer.take_overflow().is_none());
+        assert!(decode_frame(&frames[1]).is_some());
+    }
+
+    #[test]
+    fn serial_framer_reports_overflow_and_recovers() {
+        let mut framer = SerialFramer::new();
+        let stuck = vec![FRAME_START; FRAME_BUFFER_SIZE + 1];
+
+        let frames: Vec<Frame> = framer.push_bytes(&stuck).collect();
+
+        assert!(frames.is_empty());
+        assert!(framer.take_overflow().is_some());
+        // The buffer was reset, so the framer is usable again.
+        assert_eq!(framer.push_bytes(HEARTBEAT).count(), 1);
+    }
+
+    /// Spawns a real `redis-server` bound to a Unix socket in a temp
+    /// directory, so the Redis-backed tasks can be exercised end-to-end
+    /// instead of only through mocks. Killed on drop.
+    struct RedisServer {
+        _dir: tempfile::TempDir,
+        socket_path: std::path::PathBuf,
+        child: std::process::Child,
+    }
+
+    impl RedisServer {
+        /// Starts `redis-server` and blocks until it answers `PING`, or
+        /// panics after `STARTUP_TIMEOUT` - a broken sandbox should fail the
+        /// test loudly rather than hang it.
+        fn start() -> Self {
+            const STARTUP_TIMEOUT: Duration = Duration::from_secs(5);
+
+            let dir = tempfile::tempdir().expect("create temp dir for redis-server");
+            let socket_path = dir.path().join("redis.sock");
+
+            let child = std::process::Command::new("redis-server")
+                .args(["--port", "0"])
+                .arg("--unixsocket")
+                .arg(&socket_path)
+                .args(["--daemonize", "no", "--save", "", "--appendonly", "no"])
+                .stdout(std::process::Stdio::null())
+                .stderr(std::process::Stdio::null())
+                .spawn()
+                .expect("spawn redis-server (is it installed and on PATH?)");
+
+            let server = Self {
+                _dir: dir,
+                socket_path,
+                child,
+            };
+
+            let client = redis::Client::open(server.url().as_str()).expect("build redis client");
+            let deadline = Instant::now() + STARTUP_TIMEOUT;
+            loop {
+                if let Ok(mut conn) = client.get_connection() {
+                    if redis::cmd("PING").query::<String>(&mut conn).is_ok() {
+                        break;
+                    }
+                }
+                assert!(
+                    Instant::now() < deadline,
+                    "redis-server did not accept connections within {:?}",
+                    STARTUP_TIMEOUT
+                );
+                std::thread::sleep(Duration::from_millis(20));
+            }
+
+            server
+        }
+
+        fn url(&self) -> String {
+            format!("unix://{}", self.socket_path.display())
+        }
+    }
+
+    impl Drop for RedisServer {
+        fn drop(&mut self) {
+            let _ = self.child.kill();
+            let _ = self.child.wait();
+        }
+    }
+
+    #[tokio::test]
+    async fn command_channel_drives_listener_and_writes_lap_stream() {
+        let server = RedisServer::start();
+
+        let hw = Arc::new(
+            HardwareComm::new(true, &server.url(), None, 9600, 1000)
+                .await
+                .expect("connect HardwareComm to embedded redis"),
+        );
+        let app = Arc::new(Mutex::new(App::new(true)));
+
+        let listener = tokio::spawn(redis_listener_task(hw.clone(), app.clone()));
+        let commands = tokio::spawn(command_handler_task(hw.clone(), app.clone()));
+
+        // Give both subscribers a moment to finish SUBSCRIBE before publishing.
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        hw.send_command(&InMessage::Command {
+            command: "start_race".to_string(),
+            racer_id: None,
+            sensor_id: None,
+            race_time: None,
+            baudrate: None,
+        })
+        .await
+        .expect("publish start_race");
+
+        hw.send_command(&InMessage::Command {
+            command: "simulate_lap".to_string(),
+            racer_id: Some(3),
+            sensor_id: Some(2),
+            race_time: Some(5.5),
+            baudrate: None,
+        })
+        .await
+        .expect("publish simulate_lap");
+
+        // Wait for the listener to have relayed both resulting messages into App.
+        let deadline = Instant::now() + Duration::from_secs(2);
+        loop {
+            {
+                let app = app.lock().await;
+                if app.race_active && app.messages.len() >= 2 {
+                    break;
+                }
+            }
+            assert!(
+                Instant::now() < deadline,
+                "timed out waiting for commands to propagate through Redis"
+            );
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+
+        {
+            let app = app.lock().await;
+            assert!(app.race_active, "start_race should have set race_active");
+        }
+
+        // The lap event should also have landed in the durable events stream.
+        let mut stream_conn = hw.conn.clone();
+        let reply: redis::streams::StreamRangeReply = stream_conn
+            .xrange(REDIS_EVENTS_STREAM, "-", "+")
+            .await
+            .expect("read events stream");
+        assert!(
+            reply
+                .ids
+                .iter()
+                .any(|entry| entry.get::<String>("type").as_deref() == Some("lap")),
+            "expected a lap entry in the events stream"
+        );
+
+        {
+            let mut app = app.lock().await;
+            app.should_quit = true;
+        }
+        let _ = tokio::time::timeout(Duration::from_secs(1), listener).await;
+        let _ = tokio::time::timeout(Duration::from_secs(1), commands).await;
+    }
+}